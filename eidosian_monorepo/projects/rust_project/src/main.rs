@@ -1,23 +1,401 @@
 //! Main entry point for the Rust project.
 
+use std::env;
+use std::fmt;
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
 /// Result of the run function
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RunResult {
-    status: String,
+    #[serde(flatten)]
+    status: RunStatus,
     message: String,
 }
 
-/// Run the main functionality of the project
-pub fn run() -> RunResult {
+/// Outcome of a run, tagged by its `status` field.
+///
+/// The variant is internally tagged (`status: "success" | "warning" |
+/// "failure"`) and flattened into [`RunResult`], so the wire form stays a
+/// single flat object, e.g. `{"status":"failure","code":2,"cause":"...",
+/// "message":"..."}`, instead of nesting the enum under its own key.
+/// Unknown `status` tags are rejected by serde during deserialization.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RunStatus {
+    Success,
+    Warning { code: u32 },
+    Failure { code: u32, cause: String },
+}
+
+/// Output format for a [`RunResult`].
+///
+/// `Json` and `Yaml`/`Toml` target machine and human-editable consumers
+/// respectively, while `Pretty` renders a terminal-friendly multi-line form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    Json,
+    Yaml,
+    Toml,
+    Pretty,
+}
+
+impl FormatKind {
+    /// Parse a format from a `--format` flag value or the
+    /// `TERMINAL_FORGE_FORMAT` env var, e.g. `"json"`, `"yaml"`, `"toml"`,
+    /// `"pretty"`.
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Some(FormatKind::Json),
+            "yaml" | "yml" => Some(FormatKind::Yaml),
+            "toml" => Some(FormatKind::Toml),
+            "pretty" => Some(FormatKind::Pretty),
+            _ => None,
+        }
+    }
+
+    /// Determine the format to use for this run, preferring an explicit
+    /// `--format <fmt>` CLI argument over the `TERMINAL_FORGE_FORMAT` env
+    /// var, and falling back to [`FormatKind::Pretty`].
+    ///
+    /// An explicitly-supplied `--format` value that doesn't parse is an
+    /// error rather than a silent fallback, since the user asked for a
+    /// specific format and got the wrong one otherwise.
+    fn from_args_or_env(args: &[String]) -> Result<Self, UnknownFormatError> {
+        if let Some(i) = args.iter().position(|a| a == "--format") {
+            return match args.get(i + 1) {
+                Some(value) => FormatKind::parse(value).ok_or_else(|| UnknownFormatError::Invalid(value.clone())),
+                None => Err(UnknownFormatError::Missing),
+            };
+        }
+
+        Ok(env::var("TERMINAL_FORGE_FORMAT")
+            .ok()
+            .and_then(|s| FormatKind::parse(&s))
+            .unwrap_or(FormatKind::Pretty))
+    }
+}
+
+/// Error returned when a `--format` flag is malformed or its value doesn't
+/// match any [`FormatKind`].
+#[derive(Debug)]
+pub enum UnknownFormatError {
+    Missing,
+    Invalid(String),
+}
+
+impl fmt::Display for UnknownFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnknownFormatError::Missing => write!(f, "--format requires a value"),
+            UnknownFormatError::Invalid(value) => write!(
+                f,
+                "unknown --format value {value:?} (expected json, yaml, toml, or pretty)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnknownFormatError {}
+
+/// Error returned by [`RunResult::encode`] or [`RunResult::decode`].
+#[derive(Debug)]
+pub enum EncodeError {
+    Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
+    Toml(toml::ser::Error),
+    TomlDecode(toml::de::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Json(e) => write!(f, "json error: {e}"),
+            EncodeError::Yaml(e) => write!(f, "yaml error: {e}"),
+            EncodeError::Toml(e) => write!(f, "toml encode error: {e}"),
+            EncodeError::TomlDecode(e) => write!(f, "toml decode error: {e}"),
+            EncodeError::Unsupported(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+impl From<serde_json::Error> for EncodeError {
+    fn from(e: serde_json::Error) -> Self {
+        EncodeError::Json(e)
+    }
+}
+
+impl From<serde_yaml::Error> for EncodeError {
+    fn from(e: serde_yaml::Error) -> Self {
+        EncodeError::Yaml(e)
+    }
+}
+
+impl From<toml::ser::Error> for EncodeError {
+    fn from(e: toml::ser::Error) -> Self {
+        EncodeError::Toml(e)
+    }
+}
+
+impl From<toml::de::Error> for EncodeError {
+    fn from(e: toml::de::Error) -> Self {
+        EncodeError::TomlDecode(e)
+    }
+}
+
+/// Box-drawing character set used when rendering frames and borders.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoxStyle {
+    Ascii,
+    #[default]
+    Light,
+    Heavy,
+    Double,
+}
+
+/// User-editable terminal styling/theme configuration.
+///
+/// Every field carries `#[serde(default)]`, so a config file only needs to
+/// set the keys it wants to override; anything missing falls back to
+/// [`Config::default`]. Loaded via [`Config::load`] (lenient, ignores extra
+/// keys) or [`Config::load_strict`] (rejects unknown keys, useful for
+/// catching typos).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default = "Config::default_palette")]
+    pub palette: Vec<String>,
+    #[serde(default = "Config::default_foreground")]
+    pub foreground: String,
+    #[serde(default = "Config::default_background")]
+    pub background: String,
+    #[serde(default)]
+    pub box_style: BoxStyle,
+    #[serde(default = "Config::default_tab_width")]
+    pub tab_width: u8,
+}
+
+/// Mirror of [`Config`] used only to enforce strict parsing; every field
+/// means the same thing, the only difference is `deny_unknown_fields`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictConfig {
+    #[serde(default = "Config::default_palette")]
+    palette: Vec<String>,
+    #[serde(default = "Config::default_foreground")]
+    foreground: String,
+    #[serde(default = "Config::default_background")]
+    background: String,
+    #[serde(default)]
+    box_style: BoxStyle,
+    #[serde(default = "Config::default_tab_width")]
+    tab_width: u8,
+}
+
+impl From<StrictConfig> for Config {
+    fn from(s: StrictConfig) -> Self {
+        Config {
+            palette: s.palette,
+            foreground: s.foreground,
+            background: s.background,
+            box_style: s.box_style,
+            tab_width: s.tab_width,
+        }
+    }
+}
+
+impl Config {
+    fn default_palette() -> Vec<String> {
+        vec![
+            "#000000".into(),
+            "#ff0000".into(),
+            "#00ff00".into(),
+            "#ffff00".into(),
+            "#0000ff".into(),
+            "#ff00ff".into(),
+            "#00ffff".into(),
+            "#ffffff".into(),
+        ]
+    }
+
+    fn default_foreground() -> String {
+        "#ffffff".into()
+    }
+
+    fn default_background() -> String {
+        "#000000".into()
+    }
+
+    fn default_tab_width() -> u8 {
+        4
+    }
+
+    /// Load a config from `path`, auto-detecting JSON/YAML/TOML from its
+    /// extension. A missing file falls back to [`Config::default`]; an
+    /// unrecognized extension or a malformed file is an error. Unknown keys
+    /// in the file are ignored.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        Self::read(path, false)
+    }
+
+    /// Like [`Config::load`], but rejects unknown keys instead of ignoring
+    /// them, so typos in a config file surface as an error.
+    pub fn load_strict(path: &Path) -> Result<Self, ConfigError> {
+        Self::read(path, true)
+    }
+
+    fn read(path: &Path, strict: bool) -> Result<Self, ConfigError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(ConfigError::Io(e)),
+        };
+
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+
+        match ext.as_deref() {
+            Some("json") if strict => Ok(serde_json::from_str::<StrictConfig>(&contents)?.into()),
+            Some("json") => Ok(serde_json::from_str(&contents)?),
+            Some("yaml") | Some("yml") if strict => Ok(serde_yaml::from_str::<StrictConfig>(&contents)?.into()),
+            Some("yaml") | Some("yml") => Ok(serde_yaml::from_str(&contents)?),
+            Some("toml") if strict => Ok(toml::from_str::<StrictConfig>(&contents)?.into()),
+            Some("toml") => Ok(toml::from_str(&contents)?),
+            _ => Err(ConfigError::UnknownExtension(path.to_path_buf())),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            palette: Config::default_palette(),
+            foreground: Config::default_foreground(),
+            background: Config::default_background(),
+            box_style: BoxStyle::default(),
+            tab_width: Config::default_tab_width(),
+        }
+    }
+}
+
+/// Error returned by [`Config::load`] / [`Config::load_strict`].
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    UnknownExtension(std::path::PathBuf),
+    Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "failed to read config file: {e}"),
+            ConfigError::UnknownExtension(path) => {
+                write!(f, "cannot detect config format from extension: {}", path.display())
+            }
+            ConfigError::Json(e) => write!(f, "invalid json config: {e}"),
+            ConfigError::Yaml(e) => write!(f, "invalid yaml config: {e}"),
+            ConfigError::Toml(e) => write!(f, "invalid toml config: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        ConfigError::Json(e)
+    }
+}
+
+impl From<serde_yaml::Error> for ConfigError {
+    fn from(e: serde_yaml::Error) -> Self {
+        ConfigError::Yaml(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Toml(e)
+    }
+}
+
+impl RunResult {
+    /// Serialize this result using the requested [`FormatKind`].
+    pub fn encode(&self, fmt: FormatKind) -> Result<String, EncodeError> {
+        match fmt {
+            FormatKind::Json => Ok(serde_json::to_string_pretty(self)?),
+            FormatKind::Yaml => Ok(serde_yaml::to_string(self)?),
+            FormatKind::Toml => Ok(toml::to_string_pretty(self)?),
+            FormatKind::Pretty => Ok(format!("status: {:?}\nmessage: {}", self.status, self.message)),
+        }
+    }
+
+    /// Deserialize a result previously produced by [`RunResult::encode`].
+    ///
+    /// [`FormatKind::Pretty`] is a display-only format and cannot be decoded.
+    pub fn decode(s: &str, fmt: FormatKind) -> Result<Self, EncodeError> {
+        match fmt {
+            FormatKind::Json => Ok(serde_json::from_str(s)?),
+            FormatKind::Yaml => Ok(serde_yaml::from_str(s)?),
+            FormatKind::Toml => Ok(toml::from_str(s)?),
+            FormatKind::Pretty => Err(EncodeError::Unsupported("pretty format is not decodable")),
+        }
+    }
+}
+
+/// Run the main functionality of the project, with terminal rendering
+/// decisions driven by `config`.
+pub fn run(config: &Config) -> RunResult {
     RunResult {
-        status: String::from("success"),
-        message: String::from("Hello from Rust project!"),
+        status: RunStatus::Success,
+        message: format!(
+            "Hello from Rust project! (box_style: {:?}, tab_width: {})",
+            config.box_style, config.tab_width
+        ),
     }
 }
 
+/// Determine the config file path from a `--config <path>` CLI argument,
+/// defaulting to `terminal_forge.toml` in the current directory.
+fn config_path_from_args(args: &[String]) -> std::path::PathBuf {
+    args.iter()
+        .position(|a| a == "--config")
+        .and_then(|i| args.get(i + 1))
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| std::path::PathBuf::from("terminal_forge.toml"))
+}
+
 fn main() {
-    let result = run();
-    println!("Result: {:?}", result);
+    let args: Vec<String> = env::args().collect();
+    let format = match FormatKind::from_args_or_env(&args) {
+        Ok(format) => format,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    };
+    let config_path = config_path_from_args(&args);
+
+    let config = match Config::load(&config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("failed to load config, using defaults: {err}");
+            Config::default()
+        }
+    };
+
+    let result = run(&config);
+    match result.encode(format) {
+        Ok(encoded) => println!("{encoded}"),
+        Err(err) => eprintln!("failed to encode result: {err}"),
+    }
 }